@@ -19,11 +19,15 @@ println!("{}", err); // GraphinaError { kind: Other, message: "a generic error"
 
 use std::error::Error;
 use std::fmt;
+use std::num::{ParseFloatError, ParseIntError};
 
 #[derive(Debug, Clone)]
 pub struct GraphinaError {
     pub kind: GraphinaErrorKind,
     pub message: String,
+    // `Arc` rather than `Box` so `GraphinaError` keeps deriving `Clone`, which
+    // callers across the crate already rely on.
+    source: Option<std::sync::Arc<dyn Error + Send + Sync>>,
 }
 
 impl std::fmt::Display for GraphinaError {
@@ -35,73 +39,102 @@ impl std::fmt::Display for GraphinaError {
     }
 }
 
-impl Error for GraphinaError {}
+impl Error for GraphinaError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|e| e.as_ref() as &(dyn Error + 'static))
+    }
+}
 
 impl GraphinaError {
+    /// Attaches an underlying cause to this error, preserving the error chain
+    /// so callers can walk it via [`std::error::Error::source`].
+    pub fn with_source(mut self, source: impl Error + Send + Sync + 'static) -> GraphinaError {
+        self.source = Some(std::sync::Arc::new(source));
+        self
+    }
+
     pub fn pointless(message: impl Into<String>) -> GraphinaError {
         GraphinaError {
             kind: GraphinaErrorKind::PointlessConcept,
             message: message.into(),
+            source: None,
         }
     }
     pub fn algorithm_error(message: impl Into<String>) -> GraphinaError {
         GraphinaError {
             kind: GraphinaErrorKind::AlgorithmError,
             message: message.into(),
+            source: None,
         }
     }
     pub fn unfeasible(message: impl Into<String>) -> GraphinaError {
         GraphinaError {
             kind: GraphinaErrorKind::Unfeasible,
             message: message.into(),
+            source: None,
         }
     }
     pub fn no_path(message: impl Into<String>) -> GraphinaError {
         GraphinaError {
             kind: GraphinaErrorKind::NoPath,
             message: message.into(),
+            source: None,
         }
     }
     pub fn no_cycle(message: impl Into<String>) -> GraphinaError {
         GraphinaError {
             kind: GraphinaErrorKind::NoCycle,
             message: message.into(),
+            source: None,
         }
     }
     pub fn node_not_found(message: impl Into<String>) -> GraphinaError {
         GraphinaError {
             kind: GraphinaErrorKind::NodeNotFound,
             message: message.into(),
+            source: None,
         }
     }
     pub fn edge_not_found(message: impl Into<String>) -> GraphinaError {
         GraphinaError {
             kind: GraphinaErrorKind::EdgeNotFound,
             message: message.into(),
+            source: None,
         }
     }
-    pub fn has_a_cycle(message: impl Into<String>) -> GraphinaError {
+    /// Builds a [`GraphinaErrorKind::HasACycle`] error from the offending
+    /// cycle, as an ordered list of caller-facing node ids (e.g. `[a, b, c,
+    /// a]`).
+    pub fn has_a_cycle(cycle: Vec<crate::core::types::NodeId>) -> GraphinaError {
+        let kind = GraphinaErrorKind::HasACycle { cycle };
+        let message = kind.to_string();
         GraphinaError {
-            kind: GraphinaErrorKind::HasACycle,
-            message: message.into(),
+            kind,
+            message,
+            source: None,
         }
     }
     pub fn unbounded(message: impl Into<String>) -> GraphinaError {
         GraphinaError {
             kind: GraphinaErrorKind::Unbounded,
             message: message.into(),
+            source: None,
         }
     }
     pub fn ambiguous_solution(message: impl Into<String>) -> GraphinaError {
         GraphinaError {
             kind: GraphinaErrorKind::AmbiguousSolution,
             message: message.into(),
+            source: None,
         }
     }
     pub fn exceeded_max_iteration(message: impl Into<String>) -> GraphinaError {
         GraphinaError {
             kind: GraphinaErrorKind::ExceededMaxIterations,
             message: message.into(),
+            source: None,
         }
     }
     pub fn power_iteration_failed_converge(
@@ -111,96 +144,153 @@ impl GraphinaError {
         GraphinaError {
             kind: GraphinaErrorKind::PowerIterationFailedConvergence { num_iterations },
             message: message.into(),
+            source: None,
         }
     }
     pub fn empty_graph(message: impl Into<String>) -> GraphinaError {
         GraphinaError {
             kind: GraphinaErrorKind::EmptyGraph,
             message: message.into(),
+            source: None,
         }
     }
     pub fn parse_error(message: impl Into<String>) -> GraphinaError {
         GraphinaError {
             kind: GraphinaErrorKind::ParseError,
             message: message.into(),
+            source: None,
+        }
+    }
+    /// Builds an [`GraphinaErrorKind::Io`] error from a message alone; no
+    /// underlying cause is attached (see [`GraphinaError::with_source`]).
+    pub fn io(message: impl Into<String>) -> GraphinaError {
+        GraphinaError {
+            kind: GraphinaErrorKind::Io,
+            message: message.into(),
+            source: None,
         }
     }
     pub fn other(message: impl Into<String>) -> GraphinaError {
         GraphinaError {
             kind: GraphinaErrorKind::Other,
             message: message.into(),
+            source: None,
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, thiserror::Error)]
+#[non_exhaustive]
 pub enum GraphinaErrorKind {
     /// Exception raised when a graph is provided to an algorithm that cannot use it.
     ///
     /// This error indicates that an algorithm received an invalid graph input like a null/empty graph.
+    #[error("pointless concept")]
     PointlessConcept,
     /// Exception for unexpected termination of algorithms.
     ///
     /// This error is used when an algorithm terminates unexpectedly.
+    #[error("algorithm error")]
     AlgorithmError,
     /// Exception raised when no feasible solution exists.
     ///
     /// This error indicates that an algorithm failed to find a viable solution (e.g., optimization).
+    #[error("unfeasible")]
     Unfeasible,
     /// Exception raised when no path exists between nodes.
     ///
     /// This error is returned when an algorithm determines that no valid path can be found.
+    #[error("no path")]
     NoPath,
     /// Exception raised when no cycle exists in a graph.
     ///
     /// This error is used when an algorithm expects a cycle but none is found in the graph.
+    #[error("no cycle")]
     NoCycle,
     /// Exception raised if a requested node is not found.
     ///
     /// This error is typically returned when an operation attempts to reference a non-existent node.
+    #[error("node not found")]
     NodeNotFound,
     /// Exception raised if a requested edge is not found.
     ///
     /// This error is typically returned when an operation attempts to reference a non-existent edge.
+    #[error("edge not found")]
     EdgeNotFound,
     /// Exception raised if a graph has a cycle when an acyclic structure is expected.
     ///
-    /// This error indicates that a cycle was found in a graph where it should not exist.
-    HasACycle,
+    /// This error indicates that a cycle was found in a graph where it should not exist,
+    /// and carries the offending cycle as an ordered list of the caller-facing node ids
+    /// that form it, e.g. `[a, b, c, a]`.
+    #[error("has a cycle: {}", cycle.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(" -> "))]
+    HasACycle {
+        cycle: Vec<crate::core::types::NodeId>,
+    },
     /// Exception raised when an optimization problem is unbounded.
     ///
     /// This error is used when an algorithm detects that the solution is unbounded (e.g., linear programming).
+    #[error("unbounded")]
     Unbounded,
     /// Exception raised for unimplemented algorithms for a given graph type.
     ///
     /// This error indicates that a requested algorithm or feature is not yet available.
+    #[error("not implemented")]
     NotImplemented,
     /// Raised when more than one valid solution exists for an intermediary step.
     ///
     /// This error is used when an algorithm encounters ambiguity during a computational step (e.g., optimization).
+    #[error("ambiguous solution")]
     AmbiguousSolution,
     /// Raised if a loop iterates too many times without convergence.
     ///
     /// This error signals that an iterative algorithm has exceeded the allowed iteration limit.
+    #[error("exceeded max iterations")]
     ExceededMaxIterations,
     /// Raised when the power iteration method fails to converge within the iteration limit (e.g., PageRank).
     ///
     /// This error includes the number of iterations attempted before failure.
+    #[error("power iteration failed to converge after {num_iterations} iterations")]
     PowerIterationFailedConvergence { num_iterations: usize },
-    /// Raised if tried to run algorithm that assume non-empty graph on empty graph  
+    /// Raised if tried to run algorithm that assume non-empty graph on empty graph
     ///
     /// This error signals that the target graph is empty when it is assumed not to be.
+    #[error("empty graph")]
     EmptyGraph,
     /// Raised if encounter error during parsing file format
     ///
     /// This error indicate that the target file might not be in the correct format
+    #[error("parse error")]
     ParseError,
+    /// Raised when an I/O operation backing a graph load/save fails.
+    ///
+    /// This error wraps the underlying [`std::io::Error`] via [`GraphinaError::source`].
+    #[error("io error")]
+    Io,
     /// Raised if encounter unexpected
     ///
     /// This error return when the particlar error doesn't fall under any other error kind.
+    #[error("other error")]
     Other,
 }
 
+impl From<std::io::Error> for GraphinaError {
+    fn from(err: std::io::Error) -> GraphinaError {
+        GraphinaError::io(err.to_string()).with_source(err)
+    }
+}
+
+impl From<ParseIntError> for GraphinaError {
+    fn from(err: ParseIntError) -> GraphinaError {
+        GraphinaError::parse_error(err.to_string()).with_source(err)
+    }
+}
+
+impl From<ParseFloatError> for GraphinaError {
+    fn from(err: ParseFloatError) -> GraphinaError {
+        GraphinaError::parse_error(err.to_string()).with_source(err)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -242,4 +332,24 @@ mod tests {
             assert_eq!(format!("{}", e), s)
         }
     }
+
+    #[test]
+    fn test_graphina_error_source_chain() {
+        let io_err = std::io::Error::new(std::io::ErrorKind::NotFound, "missing file");
+        let err: GraphinaError = io_err.into();
+        assert!(matches!(err.kind, GraphinaErrorKind::Io));
+        assert!(err.source().is_some());
+
+        let parse_err: Result<i32, _> = "not a number".parse();
+        let err: GraphinaError = parse_err.unwrap_err().into();
+        assert!(matches!(err.kind, GraphinaErrorKind::ParseError));
+        assert!(err.source().is_some());
+    }
+
+    #[test]
+    fn test_has_a_cycle_message_matches_kind_display() {
+        let err = GraphinaError::has_a_cycle(vec![1, 2, 3, 1]);
+        assert_eq!(err.message, err.kind.to_string());
+        assert_eq!(err.message, "has a cycle: 1 -> 2 -> 3 -> 1");
+    }
 }
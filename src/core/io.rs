@@ -0,0 +1,275 @@
+/*!
+# Graph I/O
+
+Reading and writing graphs to disk. This module provides a compact binary
+round-trip (`save_to` / `load_from`)
+modeled on `graphannis`'s `GraphStorage::save_to` / `load_from` /
+`serialization_id`: persisting a large graph generated via
+[`crate::core::generators`] this way is far faster than re-parsing text,
+and the embedded format version lets a stale file be rejected cleanly
+instead of silently mis-parsed.
+*/
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use super::error::GraphinaError;
+use super::types::{Graph, NodeId};
+
+/// Magic tag written at the start of every binary graph file.
+const MAGIC: &[u8; 4] = b"GRPH";
+
+/// The binary format version written by this build. Bumped whenever the
+/// on-disk layout changes in an incompatible way.
+const SERIALIZATION_ID: &str = "graphina-bin-v1";
+
+/// A node or edge weight that can be round-tripped through the binary
+/// format used by [`save_to`] / [`load_from`].
+pub trait BinaryWeight: Sized {
+    fn encode(&self, writer: &mut impl Write) -> io::Result<()>;
+    fn decode(reader: &mut impl Read) -> io::Result<Self>;
+}
+
+macro_rules! impl_binary_weight_int {
+    ($($ty:ty),*) => {
+        $(
+            impl BinaryWeight for $ty {
+                fn encode(&self, writer: &mut impl Write) -> io::Result<()> {
+                    writer.write_all(&self.to_le_bytes())
+                }
+                fn decode(reader: &mut impl Read) -> io::Result<Self> {
+                    let mut buf = [0u8; std::mem::size_of::<$ty>()];
+                    reader.read_exact(&mut buf)?;
+                    Ok(<$ty>::from_le_bytes(buf))
+                }
+            }
+        )*
+    };
+}
+
+impl_binary_weight_int!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+// `usize`/`isize` are deliberately not in `impl_binary_weight_int!`: their
+// width varies by platform, so encoding them natively would break this
+// format's cross-platform round-trip guarantee. Fix their width at 64 bits
+// instead, matching how node/edge ids are already carried as `u64` on the
+// wire.
+impl BinaryWeight for usize {
+    fn encode(&self, writer: &mut impl Write) -> io::Result<()> {
+        (*self as u64).encode(writer)
+    }
+    fn decode(reader: &mut impl Read) -> io::Result<Self> {
+        Ok(u64::decode(reader)? as usize)
+    }
+}
+
+impl BinaryWeight for isize {
+    fn encode(&self, writer: &mut impl Write) -> io::Result<()> {
+        (*self as i64).encode(writer)
+    }
+    fn decode(reader: &mut impl Read) -> io::Result<Self> {
+        Ok(i64::decode(reader)? as isize)
+    }
+}
+
+impl BinaryWeight for String {
+    fn encode(&self, writer: &mut impl Write) -> io::Result<()> {
+        let bytes = self.as_bytes();
+        (bytes.len() as u64).encode(writer)?;
+        writer.write_all(bytes)
+    }
+    fn decode(reader: &mut impl Read) -> io::Result<Self> {
+        let len = u64::decode(reader)?;
+        // Read via `take` rather than pre-allocating `len` bytes up front: a
+        // corrupted or malicious file can claim an arbitrarily large length,
+        // and `read_to_end` only grows the buffer as bytes actually arrive.
+        let mut buf = Vec::new();
+        reader.take(len).read_to_end(&mut buf)?;
+        if buf.len() as u64 != len {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "string length prefix exceeds remaining data",
+            ));
+        }
+        String::from_utf8(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+/// Writes `graph` to `path` as a self-describing binary file: a magic tag
+/// and [`SERIALIZATION_ID`], followed by the node table and edge table with
+/// their weights.
+pub fn save_to<N, E>(graph: &Graph<N, E>, path: impl AsRef<Path>) -> Result<(), GraphinaError>
+where
+    N: BinaryWeight,
+    E: BinaryWeight,
+{
+    let file = File::create(path)?;
+    let mut writer = BufWriter::new(file);
+
+    writer.write_all(MAGIC)?;
+    SERIALIZATION_ID.to_string().encode(&mut writer)?;
+
+    let node_ids: Vec<NodeId> = graph.node_ids().collect();
+    (node_ids.len() as u64).encode(&mut writer)?;
+    for &id in &node_ids {
+        (id as u64).encode(&mut writer)?;
+        let weight = graph
+            .node_weight(id)
+            .expect("node_ids() only yields ids present in the graph");
+        weight.encode(&mut writer)?;
+    }
+
+    let edges: Vec<(NodeId, NodeId, &E)> = node_ids
+        .iter()
+        .flat_map(|&source| {
+            graph
+                .edges_from(source)
+                .map(move |(target, weight)| (source, target, weight))
+        })
+        .collect();
+    (edges.len() as u64).encode(&mut writer)?;
+    for (source, target, weight) in edges {
+        (source as u64).encode(&mut writer)?;
+        (target as u64).encode(&mut writer)?;
+        weight.encode(&mut writer)?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads a graph previously written by [`save_to`], rejecting files whose
+/// magic tag or [`SERIALIZATION_ID`] don't match this build with
+/// [`GraphinaError::parse_error`].
+pub fn load_from<N, E>(path: impl AsRef<Path>) -> Result<Graph<N, E>, GraphinaError>
+where
+    N: BinaryWeight,
+    E: BinaryWeight,
+{
+    let file = File::open(path)?;
+    let mut reader = BufReader::new(file);
+
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(GraphinaError::parse_error(format!(
+            "not a graphina binary graph file (bad magic {magic:?})"
+        )));
+    }
+
+    let version = String::decode(&mut reader)?;
+    if version != SERIALIZATION_ID {
+        return Err(GraphinaError::parse_error(format!(
+            "incompatible graph file version: expected `{SERIALIZATION_ID}`, found `{version}`"
+        )));
+    }
+
+    let mut graph = Graph::new();
+    let mut id_map = std::collections::HashMap::new();
+
+    let node_count = u64::decode(&mut reader)?;
+    for _ in 0..node_count {
+        let stored_id = u64::decode(&mut reader)?;
+        let weight = N::decode(&mut reader)?;
+        let id = graph.add_node(weight);
+        id_map.insert(stored_id as NodeId, id);
+    }
+
+    let edge_count = u64::decode(&mut reader)?;
+    for _ in 0..edge_count {
+        let stored_source = u64::decode(&mut reader)? as NodeId;
+        let stored_target = u64::decode(&mut reader)? as NodeId;
+        let weight = E::decode(&mut reader)?;
+        let source = *id_map
+            .get(&stored_source)
+            .ok_or_else(|| GraphinaError::parse_error("edge references unknown node id"))?;
+        let target = *id_map
+            .get(&stored_target)
+            .ok_or_else(|| GraphinaError::parse_error("edge references unknown node id"))?;
+        graph.add_edge(source, target, weight)?;
+    }
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::error::GraphinaErrorKind;
+
+    #[test]
+    fn test_usize_encodes_at_fixed_64_bit_width() {
+        // `usize`'s on-disk width must stay fixed at 8 bytes regardless of
+        // the build's pointer width, so files round-trip across platforms.
+        let mut buf = Vec::new();
+        42usize.encode(&mut buf).unwrap();
+        assert_eq!(buf.len(), 8);
+        assert_eq!(usize::decode(&mut buf.as_slice()).unwrap(), 42);
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let mut graph: Graph<u64, f64> = Graph::new();
+        let a = graph.add_node(1);
+        let b = graph.add_node(2);
+        let c = graph.add_node(3);
+        graph.add_edge(a, b, 1.5).unwrap();
+        graph.add_edge(b, c, 2.5).unwrap();
+
+        let path = std::env::temp_dir().join("graphina_io_round_trip_test.bin");
+        save_to(&graph, &path).unwrap();
+        let loaded: Graph<u64, f64> = load_from(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let mut weights: Vec<u64> = loaded
+            .node_ids()
+            .filter_map(|n| loaded.node_weight(n).copied())
+            .collect();
+        weights.sort();
+        assert_eq!(weights, vec![1, 2, 3]);
+        assert_eq!(loaded.node_count(), 3);
+    }
+
+    #[test]
+    fn test_load_from_rejects_bad_magic() {
+        let path = std::env::temp_dir().join("graphina_io_bad_magic_test.bin");
+        std::fs::write(&path, b"NOPE").unwrap();
+        let result: Result<Graph<u64, f64>, _> = load_from(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(
+            result.unwrap_err().kind,
+            GraphinaErrorKind::ParseError
+        ));
+    }
+
+    #[test]
+    fn test_load_from_rejects_mismatched_version() {
+        let path = std::env::temp_dir().join("graphina_io_bad_version_test.bin");
+        let mut bytes = MAGIC.to_vec();
+        "graphina-bin-v0".to_string().encode(&mut bytes).unwrap();
+        std::fs::write(&path, &bytes).unwrap();
+        let result: Result<Graph<u64, f64>, _> = load_from(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(
+            result.unwrap_err().kind,
+            GraphinaErrorKind::ParseError
+        ));
+    }
+
+    #[test]
+    fn test_string_decode_rejects_oversized_length_prefix() {
+        // A length prefix far larger than the actual remaining bytes must
+        // surface as an `io::Error`, not abort the process by pre-allocating
+        // a buffer of the claimed size.
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(1_000_000_000_000u64).to_le_bytes());
+        bytes.extend_from_slice(b"short");
+        let mut reader = std::io::Cursor::new(bytes);
+
+        let err = String::decode(&mut reader).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
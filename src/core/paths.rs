@@ -0,0 +1,310 @@
+/*!
+# Path Algorithms
+
+Shortest- and longest-path style algorithms over [`crate::core::types::Graph`].
+*/
+
+use std::collections::{HashMap, VecDeque};
+use std::ops::{Add, Bound};
+
+use super::error::GraphinaError;
+use super::traversal::topological_sort;
+use super::types::{Graph, NodeId};
+
+/// Returns the maximum-weight path through the directed acyclic graph
+/// `graph`, together with its total weight, mirroring rustworkx's
+/// `dag_algo::longest_path`.
+///
+/// `weight_fn` maps an edge's weight to its numeric weight, and may itself
+/// fail with `E`. Returns [`GraphinaError::has_a_cycle`]
+/// if `graph` is not actually acyclic.
+///
+/// An empty graph yields `Ok(Some((vec![], W::default())))`; a graph with no
+/// edges yields a single one of its nodes with zero weight.
+pub fn longest_path<N, Edge, W, E>(
+    graph: &Graph<N, Edge>,
+    weight_fn: impl Fn(&Edge) -> Result<W, E>,
+) -> Result<Option<(Vec<NodeId>, W)>, GraphinaError>
+where
+    W: Add<Output = W> + PartialOrd + Default + Copy,
+    E: std::fmt::Display,
+{
+    let order = topological_sort(graph)?;
+
+    if order.is_empty() {
+        return Ok(Some((vec![], W::default())));
+    }
+
+    let mut dist: HashMap<NodeId, W> = order.iter().map(|&n| (n, W::default())).collect();
+    let mut predecessor: HashMap<NodeId, NodeId> = HashMap::new();
+
+    for &u in &order {
+        for (v, edge_weight) in graph.edges_from(u) {
+            let w = weight_fn(edge_weight).map_err(|e| {
+                GraphinaError::algorithm_error(format!("failed to weigh edge: {e}"))
+            })?;
+            let candidate = dist[&u] + w;
+            if candidate > dist[&v] {
+                dist.insert(v, candidate);
+                predecessor.insert(v, u);
+            }
+        }
+    }
+
+    let best = order
+        .iter()
+        .copied()
+        .max_by(|a, b| {
+            dist[a]
+                .partial_cmp(&dist[b])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .expect("order is non-empty");
+
+    let mut path = vec![best];
+    let mut current = best;
+    while let Some(&prev) = predecessor.get(&current) {
+        path.push(prev);
+        current = prev;
+    }
+    path.reverse();
+
+    Ok(Some((path, dist[&best])))
+}
+
+/// Returns every node reachable from `node` whose hop-distance `d` satisfies
+/// `min_distance <= d` and `d` falls within `max_distance`, following the
+/// `graphannis` `GraphStorage::find_connected` convention of a BFS bounded
+/// by a `Bound<usize>` rather than a single capped depth.
+///
+/// The search runs lazily as a BFS, so callers using `Bound::Unbounded` can
+/// stop consuming the iterator early instead of forcing a full traversal.
+pub fn find_connected<N, E>(
+    graph: &Graph<N, E>,
+    node: NodeId,
+    min_distance: usize,
+    max_distance: Bound<usize>,
+) -> Result<impl Iterator<Item = NodeId> + '_, GraphinaError> {
+    bounded_bfs(graph, node, min_distance, max_distance, false)
+}
+
+/// The reverse of [`find_connected`]: follows incoming edges instead of
+/// outgoing ones.
+pub fn find_connected_inverse<N, E>(
+    graph: &Graph<N, E>,
+    node: NodeId,
+    min_distance: usize,
+    max_distance: Bound<usize>,
+) -> Result<impl Iterator<Item = NodeId> + '_, GraphinaError> {
+    bounded_bfs(graph, node, min_distance, max_distance, true)
+}
+
+fn bounded_bfs<N, E>(
+    graph: &Graph<N, E>,
+    node: NodeId,
+    min_distance: usize,
+    max_distance: Bound<usize>,
+    inverse: bool,
+) -> Result<impl Iterator<Item = NodeId> + '_, GraphinaError> {
+    if !graph.contains_node(node) {
+        return Err(GraphinaError::node_not_found(format!(
+            "no node with id {node}"
+        )));
+    }
+
+    let mut visited: HashMap<NodeId, usize> = HashMap::new();
+    visited.insert(node, 0);
+    let mut queue = VecDeque::new();
+    queue.push_back((node, 0usize));
+
+    let within_max = move |d: usize| match max_distance {
+        Bound::Unbounded => true,
+        Bound::Included(k) => d <= k,
+        Bound::Excluded(k) => d < k,
+    };
+
+    let results = std::iter::from_fn(move || {
+        while let Some((current, distance)) = queue.pop_front() {
+            if within_max(distance + 1) || distance == 0 {
+                let neighbors: Vec<NodeId> = if inverse {
+                    graph.neighbors_incoming(current).collect()
+                } else {
+                    graph.neighbors(current).collect()
+                };
+                for next in neighbors {
+                    if let std::collections::hash_map::Entry::Vacant(e) = visited.entry(next) {
+                        e.insert(distance + 1);
+                        queue.push_back((next, distance + 1));
+                    }
+                }
+            }
+
+            if distance >= min_distance && within_max(distance) {
+                return Some(current);
+            }
+        }
+        None
+    });
+
+    Ok(results)
+}
+
+/// Returns the shortest hop-count from `source` to `target`, or `None` if
+/// `target` is unreachable.
+pub fn distance<N, E>(
+    graph: &Graph<N, E>,
+    source: NodeId,
+    target: NodeId,
+) -> Result<Option<usize>, GraphinaError> {
+    if !graph.contains_node(target) {
+        return Err(GraphinaError::node_not_found(format!(
+            "no node with id {target}"
+        )));
+    }
+
+    for (node, d) in bfs_distances(graph, source)? {
+        if node == target {
+            return Ok(Some(d));
+        }
+    }
+    Ok(None)
+}
+
+/// Returns whether `target` is reachable from `source` at a hop-distance of
+/// at least `min_distance` that falls within `max_distance`.
+pub fn is_connected<N, E>(
+    graph: &Graph<N, E>,
+    source: NodeId,
+    target: NodeId,
+    min_distance: usize,
+    max_distance: Bound<usize>,
+) -> Result<bool, GraphinaError> {
+    if !graph.contains_node(target) {
+        return Err(GraphinaError::node_not_found(format!(
+            "no node with id {target}"
+        )));
+    }
+
+    Ok(find_connected(graph, source, min_distance, max_distance)?.any(|n| n == target))
+}
+
+fn bfs_distances<N, E>(
+    graph: &Graph<N, E>,
+    source: NodeId,
+) -> Result<Vec<(NodeId, usize)>, GraphinaError> {
+    if !graph.contains_node(source) {
+        return Err(GraphinaError::node_not_found(format!(
+            "no node with id {source}"
+        )));
+    }
+
+    let mut visited: HashMap<NodeId, usize> = HashMap::new();
+    visited.insert(source, 0);
+    let mut queue = VecDeque::new();
+    queue.push_back(source);
+    let mut order = vec![(source, 0)];
+
+    while let Some(current) = queue.pop_front() {
+        let distance = visited[&current];
+        for next in graph.neighbors(current) {
+            if let std::collections::hash_map::Entry::Vacant(e) = visited.entry(next) {
+                e.insert(distance + 1);
+                order.push((next, distance + 1));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::Graph;
+
+    #[test]
+    fn test_longest_path() {
+        let mut graph: Graph<&str, i64> = Graph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b, 1).unwrap();
+        graph.add_edge(b, c, 5).unwrap();
+        graph.add_edge(a, c, 1).unwrap();
+
+        let (path, weight) =
+            longest_path::<_, _, i64, std::convert::Infallible>(&graph, |w| Ok(*w))
+                .unwrap()
+                .unwrap();
+        assert_eq!(path, vec![a, b, c]);
+        assert_eq!(weight, 6);
+    }
+
+    #[test]
+    fn test_longest_path_empty_graph() {
+        let graph: Graph<&str, i64> = Graph::new();
+        let (path, weight) =
+            longest_path::<_, _, i64, std::convert::Infallible>(&graph, |w| Ok(*w))
+                .unwrap()
+                .unwrap();
+        assert!(path.is_empty());
+        assert_eq!(weight, 0);
+    }
+
+    #[test]
+    fn test_find_connected_respects_bounds() {
+        let mut graph: Graph<&str, ()> = Graph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b, ()).unwrap();
+        graph.add_edge(b, c, ()).unwrap();
+
+        let reachable: Vec<_> = find_connected(&graph, a, 1, Bound::Included(1))
+            .unwrap()
+            .collect();
+        assert_eq!(reachable, vec![b]);
+
+        assert_eq!(distance(&graph, a, c).unwrap(), Some(2));
+        assert_eq!(distance(&graph, c, a).unwrap(), None);
+        assert!(is_connected(&graph, a, c, 0, Bound::Unbounded).unwrap());
+        assert!(!is_connected(&graph, a, c, 0, Bound::Excluded(2)).unwrap());
+    }
+
+    #[test]
+    fn test_is_connected_rejects_unknown_target() {
+        let mut graph: Graph<&str, ()> = Graph::new();
+        let a = graph.add_node("a");
+        graph.add_node("b");
+
+        let err = is_connected(&graph, a, 42, 0, Bound::Unbounded).unwrap_err();
+        assert!(matches!(
+            err.kind,
+            crate::core::error::GraphinaErrorKind::NodeNotFound
+        ));
+    }
+
+    #[test]
+    fn test_find_connected_inverse_respects_bounds() {
+        let mut graph: Graph<&str, ()> = Graph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b, ()).unwrap();
+        graph.add_edge(b, c, ()).unwrap();
+
+        // Walking backwards from the sink `c` must mirror `find_connected`
+        // but over incoming edges.
+        let reachable: Vec<_> = find_connected_inverse(&graph, c, 1, Bound::Included(1))
+            .unwrap()
+            .collect();
+        assert_eq!(reachable, vec![b]);
+
+        let mut reachable: Vec<_> = find_connected_inverse(&graph, c, 0, Bound::Unbounded)
+            .unwrap()
+            .collect();
+        reachable.sort();
+        assert_eq!(reachable, vec![a, b, c]);
+    }
+}
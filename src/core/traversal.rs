@@ -0,0 +1,336 @@
+/*!
+# Graph Traversal
+
+Traversal primitives shared by the algorithms in [`crate::core::paths`],
+[`crate::core::mst`] and elsewhere - depth-first search, topological
+ordering, and cycle detection.
+*/
+
+use std::collections::HashMap;
+
+use super::error::GraphinaError;
+use super::types::{Graph, NodeId};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Color {
+    White,
+    Gray,
+    Black,
+}
+
+/// Topologically sorts `graph`, returning [`GraphinaError::has_a_cycle`]
+/// with the offending cycle if the graph is not a DAG.
+pub fn topological_sort<N, E>(graph: &Graph<N, E>) -> Result<Vec<NodeId>, GraphinaError> {
+    let mut color: HashMap<NodeId, Color> = graph.node_ids().map(|n| (n, Color::White)).collect();
+    let mut predecessor: HashMap<NodeId, NodeId> = HashMap::new();
+    let mut order = Vec::with_capacity(graph.node_count());
+
+    for start in graph.node_ids() {
+        if color[&start] == Color::White {
+            visit(graph, start, &mut color, &mut predecessor, &mut order)?;
+        }
+    }
+
+    order.reverse();
+    Ok(order)
+}
+
+/// Visits `start` and everything reachable from it with an explicit-stack
+/// DFS rather than one native stack frame per node: this is the shared
+/// backbone of [`longest_path`](crate::core::paths::longest_path) and
+/// [`collect_bicolor_runs`], and a recursive walk would overflow the native
+/// stack on the large generated graphs those algorithms target.
+///
+/// Each stack frame tracks a node alongside how far we've gotten through its
+/// neighbor list, mirroring the call-stack state a recursive DFS would keep
+/// in its local variables.
+fn visit<N, E>(
+    graph: &Graph<N, E>,
+    start: NodeId,
+    color: &mut HashMap<NodeId, Color>,
+    predecessor: &mut HashMap<NodeId, NodeId>,
+    order: &mut Vec<NodeId>,
+) -> Result<(), GraphinaError> {
+    let mut stack: Vec<(NodeId, Vec<NodeId>, usize)> = Vec::new();
+    color.insert(start, Color::Gray);
+    stack.push((start, graph.neighbors(start).collect(), 0));
+
+    while let Some(frame) = stack.last_mut() {
+        let node = frame.0;
+        if frame.2 >= frame.1.len() {
+            color.insert(node, Color::Black);
+            order.push(node);
+            stack.pop();
+            continue;
+        }
+
+        let next = frame.1[frame.2];
+        frame.2 += 1;
+
+        match color.get(&next) {
+            Some(Color::White) => {
+                predecessor.insert(next, node);
+                color.insert(next, Color::Gray);
+                stack.push((next, graph.neighbors(next).collect(), 0));
+            }
+            Some(Color::Gray) => {
+                // Back-edge `node -> next`: reconstruct the cycle by walking
+                // the predecessor chain from its source (`node`) up to its
+                // target (`next`), then closing the loop.
+                return Err(GraphinaError::has_a_cycle(reconstruct_cycle(
+                    predecessor,
+                    node,
+                    next,
+                )));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn reconstruct_cycle(
+    predecessor: &HashMap<NodeId, NodeId>,
+    back_edge_source: NodeId,
+    back_edge_target: NodeId,
+) -> Vec<NodeId> {
+    let mut cycle = vec![back_edge_source];
+    let mut current = back_edge_source;
+    while current != back_edge_target {
+        current = predecessor[&current];
+        cycle.push(current);
+    }
+    cycle.reverse();
+    // Close the loop: `back_edge_target` is already the first element, so
+    // re-append it rather than `back_edge_source`.
+    cycle.push(back_edge_target);
+    cycle
+}
+
+/// Extracts maximal runs of matching nodes threaded by two edge "colors",
+/// ported from rustworkx's `collect_bicolor_runs`. Useful for pattern-
+/// grouping in pipeline/circuit-like DAGs.
+///
+/// `graph` is topologically sorted first, returning
+/// [`GraphinaError::has_a_cycle`] if it isn't a DAG. For each node in topo
+/// order, `filter_fn` decides its role: `None` skips the node without
+/// disturbing any run in progress; `Some(false)` flushes every run in
+/// progress into the output; `Some(true)` means the node participates: it
+/// takes the run pending under its incoming color (or starts a new one),
+/// appends itself, and re-keys the run under its outgoing color, or emits
+/// the run if it has no outgoing color (colors via `color_fn`, ignoring
+/// edges colored `None`). A run displaced from an outgoing-color slot by
+/// another run is emitted.
+///
+/// Invariant: a participating node has at most one relevant in-color and
+/// one out-color, and each participating node appears in exactly one
+/// emitted run; nodes filtered out with `None` or `Some(false)` appear in
+/// none.
+pub fn collect_bicolor_runs<N, Edge, E>(
+    graph: &Graph<N, Edge>,
+    filter_fn: impl Fn(&N) -> Result<Option<bool>, E>,
+    color_fn: impl Fn(&Edge) -> Result<Option<usize>, E>,
+) -> Result<Vec<Vec<NodeId>>, GraphinaError>
+where
+    E: std::fmt::Display,
+{
+    let order = topological_sort(graph)?;
+
+    let mut pending_list: HashMap<usize, Vec<NodeId>> = HashMap::new();
+    let mut block_list: Vec<Vec<NodeId>> = Vec::new();
+
+    for node in order {
+        let weight = graph
+            .node_weight(node)
+            .expect("topological_sort only yields ids present in the graph");
+        let participates = filter_fn(weight)
+            .map_err(|e| GraphinaError::algorithm_error(format!("filter_fn failed: {e}")))?;
+
+        let Some(participates) = participates else {
+            continue;
+        };
+
+        if !participates {
+            for (_, run) in pending_list.drain() {
+                block_list.push(run);
+            }
+            continue;
+        }
+
+        let c_in = single_color(graph.edges_incoming(node), &color_fn)?;
+        let c_out = single_color(graph.edges_from(node), &color_fn)?;
+
+        let mut run = c_in
+            .and_then(|c| pending_list.remove(&c))
+            .unwrap_or_default();
+        run.push(node);
+
+        match c_out {
+            Some(c) => {
+                if let Some(displaced) = pending_list.insert(c, run) {
+                    block_list.push(displaced);
+                }
+            }
+            None => block_list.push(run),
+        }
+    }
+
+    for (_, run) in pending_list.drain() {
+        block_list.push(run);
+    }
+
+    Ok(block_list)
+}
+
+/// Returns the color of the first edge whose `color_fn` gives `Some`, relying
+/// on the `collect_bicolor_runs` invariant that a participating node has at
+/// most one relevant in-color and one out-color; the remaining edges' colors
+/// are not checked. Edges colored `None` are ignored.
+fn single_color<'a, Edge: 'a, E>(
+    edges: impl Iterator<Item = (NodeId, &'a Edge)>,
+    color_fn: &impl Fn(&Edge) -> Result<Option<usize>, E>,
+) -> Result<Option<usize>, GraphinaError>
+where
+    E: std::fmt::Display,
+{
+    for (_, weight) in edges {
+        if let Some(color) = color_fn(weight)
+            .map_err(|e| GraphinaError::algorithm_error(format!("color_fn failed: {e}")))?
+        {
+            return Ok(Some(color));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::error::GraphinaErrorKind;
+    use crate::core::types::Graph;
+
+    #[test]
+    fn test_topological_sort_orders_dependencies_first() {
+        let mut graph: Graph<&str, ()> = Graph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b, ()).unwrap();
+        graph.add_edge(b, c, ()).unwrap();
+
+        assert_eq!(topological_sort(&graph).unwrap(), vec![a, b, c]);
+    }
+
+    #[test]
+    fn test_topological_sort_handles_long_chain_without_stack_overflow() {
+        // A chain deep enough to blow the native call stack if `visit` were
+        // still recursive (one frame per node).
+        const LEN: usize = 200_000;
+        let mut graph: Graph<usize, ()> = Graph::new();
+        let mut nodes = Vec::with_capacity(LEN);
+        for i in 0..LEN {
+            nodes.push(graph.add_node(i));
+        }
+        for pair in nodes.windows(2) {
+            graph.add_edge(pair[0], pair[1], ()).unwrap();
+        }
+
+        assert_eq!(topological_sort(&graph).unwrap(), nodes);
+    }
+
+    #[test]
+    fn test_topological_sort_reports_cycle_path() {
+        let mut graph: Graph<&str, ()> = Graph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b, ()).unwrap();
+        graph.add_edge(b, c, ()).unwrap();
+        graph.add_edge(c, a, ()).unwrap();
+
+        let err = topological_sort(&graph).unwrap_err();
+        match err.kind {
+            GraphinaErrorKind::HasACycle { cycle } => {
+                assert_eq!(cycle.first(), cycle.last());
+                assert_eq!(cycle.len(), 4);
+            }
+            other => panic!("expected HasACycle, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_collect_bicolor_runs() {
+        // Two unrelated same-colored chains, a --0--> b and c --1--> d,
+        // plus a node filtered out entirely (e, reachable from a) that must
+        // not disturb the run in progress.
+        let mut graph: Graph<char, usize> = Graph::new();
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        let c = graph.add_node('c');
+        let d = graph.add_node('d');
+        let e = graph.add_node('e');
+        graph.add_edge(a, b, 0).unwrap();
+        graph.add_edge(c, d, 1).unwrap();
+        graph.add_edge(a, e, 0).unwrap();
+
+        let runs = collect_bicolor_runs::<_, _, std::convert::Infallible>(
+            &graph,
+            |n| Ok(if *n == 'e' { None } else { Some(true) }),
+            |edge_color| Ok(Some(*edge_color)),
+        )
+        .unwrap();
+
+        assert_eq!(runs.len(), 2);
+        assert!(runs.contains(&vec![a, b]));
+        assert!(runs.contains(&vec![c, d]));
+    }
+
+    #[test]
+    fn test_collect_bicolor_runs_flushes_on_false_filter() {
+        // a --0--> b --1--> c, with b excluded via `Some(false)`: the run
+        // started at `a` must flush immediately rather than threading
+        // through to `c`.
+        let mut graph: Graph<char, usize> = Graph::new();
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        let c = graph.add_node('c');
+        graph.add_edge(a, b, 0).unwrap();
+        graph.add_edge(b, c, 1).unwrap();
+
+        let runs = collect_bicolor_runs::<_, _, std::convert::Infallible>(
+            &graph,
+            |n| Ok(Some(*n != 'b')),
+            |edge_color| Ok(Some(*edge_color)),
+        )
+        .unwrap();
+
+        assert_eq!(runs.len(), 2);
+        assert!(runs.contains(&vec![a]));
+        assert!(runs.contains(&vec![c]));
+    }
+
+    #[test]
+    fn test_collect_bicolor_runs_rekeys_through_color_change() {
+        // a --0--> b --1--> c --1--> d: `b` has a different in-color than
+        // out-color, so the run must re-key from `c_in` to `c_out` there and
+        // still thread all four nodes together.
+        let mut graph: Graph<char, usize> = Graph::new();
+        let a = graph.add_node('a');
+        let b = graph.add_node('b');
+        let c = graph.add_node('c');
+        let d = graph.add_node('d');
+        graph.add_edge(a, b, 0).unwrap();
+        graph.add_edge(b, c, 1).unwrap();
+        graph.add_edge(c, d, 1).unwrap();
+
+        let runs = collect_bicolor_runs::<_, _, std::convert::Infallible>(
+            &graph,
+            |_| Ok(Some(true)),
+            |edge_color| Ok(Some(*edge_color)),
+        )
+        .unwrap();
+
+        assert_eq!(runs, vec![vec![a, b, c, d]]);
+    }
+}
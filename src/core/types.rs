@@ -0,0 +1,154 @@
+/*!
+# Core Graph Types
+
+This module defines the graph representation shared by every algorithm in
+Graphina. [`Graph`] wraps a [`petgraph::graph::DiGraph`] but hides its
+internal [`petgraph::graph::NodeIndex`] behind a stable, caller-facing
+[`NodeId`]. Algorithms must only ever hand [`NodeId`]s back to callers -
+petgraph indices can shift when nodes are removed, so leaking them would be
+a correctness hazard for any long-lived graph.
+*/
+
+use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::EdgeRef;
+use petgraph::Direction;
+use std::collections::HashMap;
+
+use super::error::GraphinaError;
+
+/// A stable, caller-facing identifier for a node.
+///
+/// Unlike petgraph's internal `NodeIndex`, a `NodeId` is assigned once when
+/// the node is added and never changes for the lifetime of the graph.
+pub type NodeId = usize;
+
+/// A directed graph with generic node and edge weights.
+#[derive(Debug, Clone, Default)]
+pub struct Graph<N, E> {
+    inner: DiGraph<N, E>,
+    id_to_index: HashMap<NodeId, NodeIndex>,
+    index_to_id: HashMap<NodeIndex, NodeId>,
+    next_id: NodeId,
+}
+
+impl<N, E> Graph<N, E> {
+    pub fn new() -> Self {
+        Graph {
+            inner: DiGraph::new(),
+            id_to_index: HashMap::new(),
+            index_to_id: HashMap::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Adds a node with the given weight, returning its caller-facing id.
+    pub fn add_node(&mut self, weight: N) -> NodeId {
+        let id = self.next_id;
+        self.next_id += 1;
+        let index = self.inner.add_node(weight);
+        self.id_to_index.insert(id, index);
+        self.index_to_id.insert(index, id);
+        id
+    }
+
+    /// Adds a directed edge `source -> target`, failing if either endpoint
+    /// is unknown.
+    pub fn add_edge(
+        &mut self,
+        source: NodeId,
+        target: NodeId,
+        weight: E,
+    ) -> Result<(), GraphinaError> {
+        let source_index = self.index_of(source)?;
+        let target_index = self.index_of(target)?;
+        self.inner.add_edge(source_index, target_index, weight);
+        Ok(())
+    }
+
+    pub fn node_weight(&self, node: NodeId) -> Option<&N> {
+        let index = *self.id_to_index.get(&node)?;
+        self.inner.node_weight(index)
+    }
+
+    pub fn contains_node(&self, node: NodeId) -> bool {
+        self.id_to_index.contains_key(&node)
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.inner.node_count()
+    }
+
+    /// Iterates over every node id currently in the graph.
+    pub fn node_ids(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.id_to_index.keys().copied()
+    }
+
+    /// Iterates over the outgoing neighbors of `node`, as caller-facing ids.
+    pub fn neighbors(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let index = self.id_to_index.get(&node).copied();
+        index
+            .into_iter()
+            .flat_map(move |index| self.inner.neighbors(index))
+            .map(move |index| self.index_to_id[&index])
+    }
+
+    /// Iterates over the incoming neighbors of `node`, as caller-facing ids.
+    pub fn neighbors_incoming(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let index = self.id_to_index.get(&node).copied();
+        index
+            .into_iter()
+            .flat_map(move |index| self.inner.neighbors_directed(index, Direction::Incoming))
+            .map(move |index| self.index_to_id[&index])
+    }
+
+    /// Iterates over the outgoing edges of `node` as `(target, weight)`
+    /// pairs, using caller-facing ids for the target.
+    pub fn edges_from(&self, node: NodeId) -> impl Iterator<Item = (NodeId, &E)> + '_ {
+        let index = self.id_to_index.get(&node).copied();
+        index
+            .into_iter()
+            .flat_map(move |index| self.inner.edges(index))
+            .map(move |edge| (self.index_to_id[&edge.target()], edge.weight()))
+    }
+
+    /// Iterates over the incoming edges of `node` as `(source, weight)`
+    /// pairs, using caller-facing ids for the source.
+    pub fn edges_incoming(&self, node: NodeId) -> impl Iterator<Item = (NodeId, &E)> + '_ {
+        let index = self.id_to_index.get(&node).copied();
+        index
+            .into_iter()
+            .flat_map(move |index| self.inner.edges_directed(index, Direction::Incoming))
+            .map(move |edge| (self.index_to_id[&edge.source()], edge.weight()))
+    }
+
+    fn index_of(&self, node: NodeId) -> Result<NodeIndex, GraphinaError> {
+        self.id_to_index
+            .get(&node)
+            .copied()
+            .ok_or_else(|| GraphinaError::node_not_found(format!("no node with id {node}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_node_and_edge() {
+        let mut graph: Graph<&str, u32> = Graph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b, 1).unwrap();
+
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.node_weight(a), Some(&"a"));
+        assert_eq!(graph.neighbors(a).collect::<Vec<_>>(), vec![b]);
+    }
+
+    #[test]
+    fn test_add_edge_unknown_node() {
+        let mut graph: Graph<&str, u32> = Graph::new();
+        let a = graph.add_node("a");
+        assert!(graph.add_edge(a, 42, 1).is_err());
+    }
+}